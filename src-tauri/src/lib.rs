@@ -1,5 +1,4 @@
 use aws_config::{BehaviorVersion, Region};
-use aws_sdk_s3::config::Credentials as S3Credentials;
 use aws_sdk_s3::presigning::PresigningConfig;
 use aws_sdk_s3::Client as S3Client;
 use serde::{Deserialize, Serialize};
@@ -22,29 +21,187 @@ struct FileInfo {
     url: String,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+struct FileListPage {
+    files: Vec<FileInfo>,
+    next_start_after: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct IncompleteUpload {
+    key: String,
+    upload_id: String,
+    initiated: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CleanupResult {
+    aborted: Vec<IncompleteUpload>,
+    errors: Vec<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct PresignedGetOverrides {
+    content_disposition: Option<String>,
+    content_type: Option<String>,
+    cache_control: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PartUpload {
+    part_number: i32,
+    etag: String,
+    checksum: Option<String>,
+}
+
 #[derive(Clone)]
 struct AwsConfig {
-    access_key: String,
-    secret_key: String,
     region: String,
     bucket: String,
+    endpoint_url: Option<String>,
+    force_path_style: bool,
+    checksum_algorithm: Option<aws_sdk_s3::types::ChecksumAlgorithm>,
+    max_attempts: u32,
+    operation_timeout_secs: u64,
 }
 
 impl AwsConfig {
     fn new() -> Result<Self, String> {
         Ok(AwsConfig {
-            access_key: std::env::var("AWS_ACCESS_KEY_ID")
-                .map_err(|_| "AWS_ACCESS_KEY_ID not found")?,
-            secret_key: std::env::var("AWS_SECRET_ACCESS_KEY")
-                .map_err(|_| "AWS_SECRET_ACCESS_KEY not found")?,
             region: std::env::var("AWS_REGION")
                 .map_err(|_| "AWS_REGION not found")?,
             bucket: std::env::var("AWS_BUCKET")
                 .map_err(|_| "AWS_BUCKET not found")?,
+            endpoint_url: std::env::var("AWS_ENDPOINT_URL").ok(),
+            force_path_style: std::env::var("AWS_FORCE_PATH_STYLE")
+                .map(|v| v == "true" || v == "1")
+                .unwrap_or(false),
+            checksum_algorithm: std::env::var("AWS_CHECKSUM_ALGORITHM")
+                .ok()
+                .and_then(|v| parse_checksum_algorithm(&v)),
+            max_attempts: std::env::var("AWS_MAX_ATTEMPTS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(3),
+            operation_timeout_secs: std::env::var("AWS_OPERATION_TIMEOUT_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(30),
         })
     }
 }
 
+// Maps the `AWS_CHECKSUM_ALGORITHM` env var onto the SDK's checksum enum, so
+// integrity verification can be turned on without the caller needing to know
+// the exact S3 wire values.
+fn parse_checksum_algorithm(value: &str) -> Option<aws_sdk_s3::types::ChecksumAlgorithm> {
+    match value.to_uppercase().as_str() {
+        "CRC32" => Some(aws_sdk_s3::types::ChecksumAlgorithm::Crc32),
+        "CRC32C" => Some(aws_sdk_s3::types::ChecksumAlgorithm::Crc32C),
+        "SHA1" => Some(aws_sdk_s3::types::ChecksumAlgorithm::Sha1),
+        "SHA256" => Some(aws_sdk_s3::types::ChecksumAlgorithm::Sha256),
+        _ => None,
+    }
+}
+
+// Without `endpoint_url`, the AWS path-style URL. With one, path-style or
+// virtual-hosted-style depending on `force_path_style` (path-style also when
+// the endpoint has no scheme).
+fn object_url(aws_config: &AwsConfig, key: &str) -> String {
+    let Some(endpoint) = &aws_config.endpoint_url else {
+        return format!(
+            "https://s3.{}.amazonaws.com/{}/{}",
+            aws_config.region, aws_config.bucket, key
+        );
+    };
+
+    let endpoint = endpoint.trim_end_matches('/');
+
+    if !aws_config.force_path_style {
+        if let Some((scheme, host)) = endpoint.split_once("://") {
+            return format!("{}://{}.{}/{}", scheme, aws_config.bucket, host, key);
+        }
+    }
+
+    format!("{}/{}/{}", endpoint, aws_config.bucket, key)
+}
+
+// Resolves credentials via the AWS SDK's default provider chain (env, ECS,
+// IMDS, EKS web identity) instead of a hardcoded static pair.
+async fn build_s3_client(aws_config: &AwsConfig) -> S3Client {
+    // Standard mode retries throttling/5xx with backoff; 4xx fails fast.
+    let retry_config = aws_sdk_s3::config::retry::RetryConfig::standard()
+        .with_max_attempts(aws_config.max_attempts);
+    let timeout_config = aws_sdk_s3::config::timeout::TimeoutConfig::builder()
+        .operation_timeout(Duration::from_secs(aws_config.operation_timeout_secs))
+        .build();
+
+    let mut config_loader = aws_config::defaults(BehaviorVersion::latest())
+        .region(Region::new(aws_config.region.clone()))
+        .retry_config(retry_config)
+        .timeout_config(timeout_config);
+
+    if let Some(endpoint_url) = &aws_config.endpoint_url {
+        config_loader = config_loader.endpoint_url(endpoint_url);
+    }
+
+    let config = config_loader.load().await;
+
+    let s3_config = aws_sdk_s3::config::Builder::from(&config)
+        .force_path_style(aws_config.force_path_style)
+        .build();
+
+    S3Client::from_conf(s3_config)
+}
+
+static S3_CLIENT: tokio::sync::OnceCell<S3Client> = tokio::sync::OnceCell::const_new();
+
+// Reuses a single client across commands so the resolved credentials (and,
+// on EC2/ECS/EKS, the IMDS/ECS/STS round trip that produced them) are
+// actually cached instead of being re-fetched on every invoke.
+async fn get_s3_client(aws_config: &AwsConfig) -> S3Client {
+    S3_CLIENT
+        .get_or_init(|| build_s3_client(aws_config))
+        .await
+        .clone()
+}
+
+// Follows `next_key_marker`/`next_upload_id_marker` across pages so in-progress
+// uploads past the first ~1000 are still found.
+async fn list_all_multipart_uploads(
+    client: &S3Client,
+    bucket: &str,
+) -> Result<Vec<aws_sdk_s3::types::MultipartUpload>, String> {
+    let mut uploads = Vec::new();
+    let mut key_marker: Option<String> = None;
+    let mut upload_id_marker: Option<String> = None;
+
+    loop {
+        let output = client
+            .list_multipart_uploads()
+            .bucket(bucket)
+            .set_key_marker(key_marker.clone())
+            .set_upload_id_marker(upload_id_marker.clone())
+            .send()
+            .await
+            .map_err(|e| format!("Failed to list multipart uploads: {}", e))?;
+
+        uploads.extend(output.uploads().to_vec());
+
+        if !output.is_truncated().unwrap_or(false) {
+            break;
+        }
+
+        key_marker = output.next_key_marker().map(|s| s.to_string());
+        upload_id_marker = output.next_upload_id_marker().map(|s| s.to_string());
+        if key_marker.is_none() {
+            break;
+        }
+    }
+
+    Ok(uploads)
+}
+
 #[tauri::command]
 async fn get_app_info() -> Result<(String, String), String> {
     let aws_config = AwsConfig::new()?;
@@ -65,13 +222,7 @@ async fn generate_presigned_post(
         .as_secs();
     let key = format!("{}_{}", timestamp, filename);
 
-    let config = aws_config::defaults(BehaviorVersion::latest())
-        .region(Region::new(aws_config.region.clone()))
-        .credentials_provider(S3Credentials::new(&aws_config.access_key, &aws_config.secret_key, None, None, "uppy"))
-        .load()
-        .await;
-
-    let client = S3Client::new(&config);
+    let client = get_s3_client(&aws_config).await;
     let presigning_config = PresigningConfig::expires_in(Duration::from_secs(3600))
         .map_err(|e| format!("Failed to create presigning config: {}", e))?;
 
@@ -88,7 +239,7 @@ async fn generate_presigned_post(
     fields.insert("key".to_string(), key.clone());
     fields.insert("Content-Type".to_string(), content_type);
 
-    let file_url = format!("https://s3.{}.amazonaws.com/{}/{}", aws_config.region, aws_config.bucket, key);
+    let file_url = object_url(&aws_config, &key);
 
     Ok(PresignedPostResponse {
         url: presigned_request.uri().to_string(),
@@ -99,60 +250,124 @@ async fn generate_presigned_post(
 }
 
 #[tauri::command]
-async fn list_uploaded_files() -> Result<Vec<FileInfo>, String> {
+async fn generate_presigned_get(
+    key: String,
+    expires_secs: u64,
+    overrides: Option<PresignedGetOverrides>,
+) -> Result<String, String> {
     let aws_config = AwsConfig::new()?;
 
-    let config = aws_config::defaults(BehaviorVersion::latest())
-        .region(Region::new(aws_config.region.clone()))
-        .credentials_provider(S3Credentials::new(&aws_config.access_key, &aws_config.secret_key, None, None, "uppy"))
-        .load()
-        .await;
+    let client = get_s3_client(&aws_config).await;
+    let presigning_config = PresigningConfig::expires_in(Duration::from_secs(expires_secs))
+        .map_err(|e| format!("Failed to create presigning config: {}", e))?;
 
-    let client = S3Client::new(&config);
+    let overrides = overrides.unwrap_or_default();
 
-    let list_objects_output = client
-        .list_objects_v2()
+    let presigned_request = client
+        .get_object()
         .bucket(&aws_config.bucket)
-        .send()
+        .key(&key)
+        .set_response_content_disposition(overrides.content_disposition)
+        .set_response_content_type(overrides.content_type)
+        .set_response_cache_control(overrides.cache_control)
+        .presigned(presigning_config)
         .await
-        .map_err(|e| format!("Failed to list objects: {}", e))?;
+        .map_err(|e| format!("Failed to create presigned GET request: {}", e))?;
+
+    Ok(presigned_request.uri().to_string())
+}
+
+#[tauri::command]
+async fn list_uploaded_files(
+    prefix: Option<String>,
+    max_results: Option<i32>,
+    start_after: Option<String>,
+) -> Result<FileListPage, String> {
+    let aws_config = AwsConfig::new()?;
+
+    let client = get_s3_client(&aws_config).await;
 
     let mut files = Vec::new();
+    let mut continuation_token: Option<String> = None;
+    let mut next_start_after = None;
+
+    // Follows `continuation_token` across pages instead of settling for the
+    // first 1000-object page.
+    loop {
+        let remaining = max_results.map(|max| max - files.len() as i32);
+        if remaining.is_some_and(|remaining| remaining <= 0) {
+            break;
+        }
 
-    let objects = list_objects_output.contents();
-    for object in objects {
-        if let Some(key) = object.key() {
-            let size = object.size().unwrap_or(0);
-            let last_modified = object.last_modified()
-                .map(|t| t.to_string())
-                .unwrap_or_else(|| "Unknown".to_string());
-            let url = format!("https://s3.{}.amazonaws.com/{}/{}", aws_config.region, aws_config.bucket, key);
+        let mut request = client
+            .list_objects_v2()
+            .bucket(&aws_config.bucket)
+            .set_prefix(prefix.clone())
+            .set_continuation_token(continuation_token.clone());
 
-            files.push(FileInfo {
-                key: key.to_string(),
-                size,
-                last_modified,
-                url,
-            });
+        if continuation_token.is_none() {
+            request = request.set_start_after(start_after.clone());
+        }
+
+        if let Some(remaining) = remaining {
+            request = request.max_keys(remaining.min(1000));
+        }
+
+        let list_objects_output = request
+            .send()
+            .await
+            .map_err(|e| format!("Failed to list objects: {}", e))?;
+
+        for object in list_objects_output.contents() {
+            if let Some(key) = object.key() {
+                let size = object.size().unwrap_or(0);
+                let last_modified = object.last_modified()
+                    .map(|t| t.to_string())
+                    .unwrap_or_else(|| "Unknown".to_string());
+                let url = object_url(&aws_config, key);
+
+                files.push(FileInfo {
+                    key: key.to_string(),
+                    size,
+                    last_modified,
+                    url,
+                });
+            }
+        }
+
+        if max_results.is_some_and(|max| files.len() as i32 >= max) {
+            next_start_after = files.last().map(|file| file.key.clone());
+            break;
+        }
+
+        if !list_objects_output.is_truncated().unwrap_or(false) {
+            break;
+        }
+
+        continuation_token = list_objects_output.next_continuation_token().map(|s| s.to_string());
+        if continuation_token.is_none() {
+            break;
         }
     }
 
-    files.sort_by(|a, b| b.last_modified.cmp(&a.last_modified));
+    // Sorted newest first only when the whole bucket has been fetched; a page
+    // (`max_results` set) keeps S3's ascending-key order, so that pages read in
+    // turn through `next_start_after` stay in one consistent order.
+    if max_results.is_none() {
+        files.sort_by(|a, b| b.last_modified.cmp(&a.last_modified));
+    }
 
-    Ok(files)
+    Ok(FileListPage {
+        files,
+        next_start_after,
+    })
 }
 
 #[tauri::command]
 async fn delete_file(key: String) -> Result<(), String> {
     let aws_config = AwsConfig::new()?;
 
-    let config = aws_config::defaults(BehaviorVersion::latest())
-        .region(Region::new(aws_config.region.clone()))
-        .credentials_provider(S3Credentials::new(&aws_config.access_key, &aws_config.secret_key, None, None, "uppy"))
-        .load()
-        .await;
-
-    let client = S3Client::new(&config);
+    let client = get_s3_client(&aws_config).await;
 
     client
         .delete_object()
@@ -169,13 +384,7 @@ async fn delete_file(key: String) -> Result<(), String> {
 async fn rename_file(old_key: String, new_key: String) -> Result<(), String> {
     let aws_config = AwsConfig::new()?;
 
-    let config = aws_config::defaults(BehaviorVersion::latest())
-        .region(Region::new(aws_config.region.clone()))
-        .credentials_provider(S3Credentials::new(&aws_config.access_key, &aws_config.secret_key, None, None, "uppy"))
-        .load()
-        .await;
-
-    let client = S3Client::new(&config);
+    let client = get_s3_client(&aws_config).await;
 
     let copy_source = format!("{}/{}", aws_config.bucket, old_key);
     client
@@ -203,13 +412,7 @@ async fn rename_file(old_key: String, new_key: String) -> Result<(), String> {
 async fn set_object_acl(key: String) -> Result<(), String> {
     let aws_config = AwsConfig::new()?;
 
-    let config = aws_config::defaults(BehaviorVersion::latest())
-        .region(Region::new(aws_config.region.clone()))
-        .credentials_provider(S3Credentials::new(&aws_config.access_key, &aws_config.secret_key, None, None, "uppy"))
-        .load()
-        .await;
-
-    let client = S3Client::new(&config);
+    let client = get_s3_client(&aws_config).await;
 
     client
         .put_object_acl()
@@ -232,13 +435,7 @@ async fn copy_to_clipboard(_text: String) -> Result<(), String> {
 async fn initiate_multipart_upload(filename: String, content_type: String) -> Result<(String, String), String> {
     let aws_config = AwsConfig::new()?;
 
-    let config = aws_config::defaults(BehaviorVersion::latest())
-        .region(Region::new(aws_config.region.clone()))
-        .credentials_provider(S3Credentials::new(&aws_config.access_key, &aws_config.secret_key, None, None, "uppy"))
-        .load()
-        .await;
-
-    let client = S3Client::new(&config);
+    let client = get_s3_client(&aws_config).await;
 
     // Generate unique key with timestamp
     let timestamp = std::time::SystemTime::now()
@@ -252,6 +449,7 @@ async fn initiate_multipart_upload(filename: String, content_type: String) -> Re
         .bucket(&aws_config.bucket)
         .key(&key)
         .content_type(&content_type)
+        .set_checksum_algorithm(aws_config.checksum_algorithm.clone())
         .send()
         .await
         .map_err(|e| format!("Failed to initiate multipart upload: {}", e))?;
@@ -267,13 +465,7 @@ async fn generate_presigned_url_for_part(
 ) -> Result<String, String> {
     let aws_config = AwsConfig::new()?;
 
-    let config = aws_config::defaults(BehaviorVersion::latest())
-        .region(Region::new(aws_config.region.clone()))
-        .credentials_provider(S3Credentials::new(&aws_config.access_key, &aws_config.secret_key, None, None, "uppy"))
-        .load()
-        .await;
-
-    let client = S3Client::new(&config);
+    let client = get_s3_client(&aws_config).await;
 
     let presigned_request = client
         .upload_part()
@@ -281,6 +473,7 @@ async fn generate_presigned_url_for_part(
         .key(&key)
         .upload_id(&upload_id)
         .part_number(part_number)
+        .set_checksum_algorithm(aws_config.checksum_algorithm.clone())
         .presigned(PresigningConfig::expires_in(Duration::from_secs(3600)).unwrap())
         .await
         .map_err(|e| format!("Failed to create presigned URL for part: {}", e))?;
@@ -292,28 +485,40 @@ async fn generate_presigned_url_for_part(
 async fn complete_multipart_upload(
     upload_id: String,
     key: String,
-    parts: Vec<(i32, String)>, // (part_number, etag)
+    parts: Vec<PartUpload>,
 ) -> Result<String, String> {
     let aws_config = AwsConfig::new()?;
 
-    let config = aws_config::defaults(BehaviorVersion::latest())
-        .region(Region::new(aws_config.region.clone()))
-        .credentials_provider(S3Credentials::new(&aws_config.access_key, &aws_config.secret_key, None, None, "uppy"))
-        .load()
-        .await;
+    let client = get_s3_client(&aws_config).await;
 
-    let client = S3Client::new(&config);
-
-    // Convert parts to S3 format
-    let completed_parts: Vec<_> = parts
+    // Convert parts to S3 format, attaching the checksum under whichever
+    // algorithm this upload was initiated with so S3 rejects corrupted parts
+    // at completion time instead of silently storing a damaged object.
+    let completed_parts = parts
         .into_iter()
-        .map(|(part_number, etag)| {
-            aws_sdk_s3::types::CompletedPart::builder()
-                .part_number(part_number)
-                .e_tag(&etag)
-                .build()
+        .map(|part| {
+            let mut builder = aws_sdk_s3::types::CompletedPart::builder()
+                .part_number(part.part_number)
+                .e_tag(&part.etag);
+
+            if let Some(checksum) = part.checksum {
+                builder = match aws_config.checksum_algorithm {
+                    Some(aws_sdk_s3::types::ChecksumAlgorithm::Crc32) => builder.checksum_crc32(checksum),
+                    Some(aws_sdk_s3::types::ChecksumAlgorithm::Crc32C) => builder.checksum_crc32_c(checksum),
+                    Some(aws_sdk_s3::types::ChecksumAlgorithm::Sha1) => builder.checksum_sha1(checksum),
+                    Some(aws_sdk_s3::types::ChecksumAlgorithm::Sha256) => builder.checksum_sha256(checksum),
+                    _ => {
+                        return Err(format!(
+                            "Part {} has a checksum but no checksum algorithm is configured",
+                            part.part_number
+                        ))
+                    }
+                };
+            }
+
+            Ok(builder.build())
         })
-        .collect();
+        .collect::<Result<Vec<_>, String>>()?;
 
     let completed_multipart_upload = aws_sdk_s3::types::CompletedMultipartUpload::builder()
         .set_parts(Some(completed_parts))
@@ -339,10 +544,111 @@ async fn complete_multipart_upload(
         .await
         .map_err(|e| format!("Failed to set object ACL: {}", e))?;
 
-    let file_url = format!("https://s3.{}.amazonaws.com/{}/{}", aws_config.region, aws_config.bucket, key);
+    let file_url = object_url(&aws_config, &key);
     Ok(file_url)
 }
 
+#[tauri::command]
+async fn abort_multipart_upload(upload_id: String, key: String) -> Result<(), String> {
+    let aws_config = AwsConfig::new()?;
+
+    let client = get_s3_client(&aws_config).await;
+
+    client
+        .abort_multipart_upload()
+        .bucket(&aws_config.bucket)
+        .key(&key)
+        .upload_id(&upload_id)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to abort multipart upload: {}", e))?;
+
+    Ok(())
+}
+
+#[tauri::command]
+async fn list_incomplete_uploads() -> Result<Vec<IncompleteUpload>, String> {
+    let aws_config = AwsConfig::new()?;
+
+    let client = get_s3_client(&aws_config).await;
+
+    let raw_uploads = list_all_multipart_uploads(&client, &aws_config.bucket).await?;
+
+    let uploads = raw_uploads
+        .iter()
+        .filter_map(|upload| {
+            let key = upload.key()?.to_string();
+            let upload_id = upload.upload_id()?.to_string();
+            let initiated = upload
+                .initiated()
+                .map(|t| t.to_string())
+                .unwrap_or_else(|| "Unknown".to_string());
+
+            Some(IncompleteUpload {
+                key,
+                upload_id,
+                initiated,
+            })
+        })
+        .collect();
+
+    Ok(uploads)
+}
+
+#[tauri::command]
+async fn cleanup_stale_uploads(older_than_secs: i64) -> Result<CleanupResult, String> {
+    let aws_config = AwsConfig::new()?;
+
+    let client = get_s3_client(&aws_config).await;
+
+    let uploads = list_all_multipart_uploads(&client, &aws_config.bucket).await?;
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64;
+
+    let mut aborted = Vec::new();
+    let mut errors = Vec::new();
+
+    for upload in &uploads {
+        let (Some(key), Some(upload_id)) = (upload.key(), upload.upload_id()) else {
+            continue;
+        };
+
+        let age_secs = upload
+            .initiated()
+            .map(|t| now - t.secs())
+            .unwrap_or(0);
+
+        if age_secs <= older_than_secs {
+            continue;
+        }
+
+        let result = client
+            .abort_multipart_upload()
+            .bucket(&aws_config.bucket)
+            .key(key)
+            .upload_id(upload_id)
+            .send()
+            .await;
+
+        match result {
+            Ok(_) => aborted.push(IncompleteUpload {
+                key: key.to_string(),
+                upload_id: upload_id.to_string(),
+                initiated: upload
+                    .initiated()
+                    .map(|t| t.to_string())
+                    .unwrap_or_else(|| "Unknown".to_string()),
+            }),
+            Err(e) => errors.push(format!("Failed to abort stale upload {}: {}", key, e)),
+        }
+    }
+
+    Ok(CleanupResult { aborted, errors })
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
@@ -352,6 +658,7 @@ pub fn run() {
         .invoke_handler(tauri::generate_handler![
             get_app_info,
             generate_presigned_post,
+            generate_presigned_get,
             list_uploaded_files,
             delete_file,
             rename_file,
@@ -359,7 +666,10 @@ pub fn run() {
             copy_to_clipboard,
             initiate_multipart_upload,
             generate_presigned_url_for_part,
-            complete_multipart_upload
+            complete_multipart_upload,
+            abort_multipart_upload,
+            list_incomplete_uploads,
+            cleanup_stale_uploads
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");